@@ -0,0 +1,36 @@
+use crate::email_client::EmailClient;
+use crate::routes::{confirm, health_check, subscribe};
+use actix_web::dev::Server;
+use actix_web::web::Data;
+use actix_web::{web, App, HttpServer};
+use sqlx::PgPool;
+use std::net::TcpListener;
+
+// We need to define a wrapper type in order to retrieve the URL
+// in the `subscribe` handler. `actix-web` gives each type stored via `app_data`
+// its own distinct "slot", so a bare `String` would collide with other strings
+// the application might store as part of its state.
+pub struct ApplicationBaseUrl(pub String);
+
+pub fn run(
+    listener: TcpListener,
+    db_pool: PgPool,
+    email_client: EmailClient,
+    base_url: String,
+) -> Result<Server, std::io::Error> {
+    let db_pool = Data::new(db_pool);
+    let email_client = Data::new(email_client);
+    let base_url = Data::new(ApplicationBaseUrl(base_url));
+    let server = HttpServer::new(move || {
+        App::new()
+            .route("/health_check", web::get().to(health_check))
+            .route("/subscriptions", web::post().to(subscribe))
+            .route("/subscriptions/confirm", web::get().to(confirm))
+            .app_data(db_pool.clone())
+            .app_data(email_client.clone())
+            .app_data(base_url.clone())
+    })
+    .listen(listener)?
+    .run();
+    Ok(server)
+}
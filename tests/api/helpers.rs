@@ -2,6 +2,7 @@ use once_cell::sync::Lazy;
 use sqlx::{migrate, Connection, Executor, PgConnection, PgPool};
 use std::net::TcpListener;
 use uuid::Uuid;
+use wiremock::MockServer;
 use zero2prod::configuration::{get_configuration, DatabaseSettings};
 use zero2prod::email_client::EmailClient;
 use zero2prod::startup::run;
@@ -30,7 +31,64 @@ static TRACING: Lazy<()> = Lazy::new(|| {
 
 pub struct TestApp {
     pub address: String,
+    pub port: u16,
     pub db_pool: PgPool,
+    pub api_client: reqwest::Client,
+    pub email_server: MockServer,
+}
+
+/// A confirmation link embedded in an email, in both its `html` and `plain_text` forms.
+pub struct ConfirmationLinks {
+    pub html: reqwest::Url,
+    pub plain_text: reqwest::Url,
+}
+
+impl TestApp {
+    // Posts a `name`/`email` form body to `POST /subscriptions`, mirroring the
+    // `application/x-www-form-urlencoded` content type the endpoint expects.
+    pub async fn post_subscriptions(&self, body: String) -> reqwest::Response {
+        self.api_client
+            .post(format!("{}/subscriptions", &self.address))
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .body(body)
+            .send()
+            .await
+            .expect("Failed to execute request.")
+    }
+
+    pub async fn get_health_check(&self) -> reqwest::Response {
+        self.api_client
+            .get(format!("{}/health_check", &self.address))
+            .send()
+            .await
+            .expect("Failed to execute request.")
+    }
+
+    /// Extract the confirmation links embedded in the request the application has
+    /// made to our mock Postmark server.
+    pub fn get_confirmation_links(&self, email_request: &wiremock::Request) -> ConfirmationLinks {
+        let body: serde_json::Value = serde_json::from_slice(&email_request.body).unwrap();
+
+        let get_link = |s: &str| {
+            let links: Vec<_> = linkify::LinkFinder::new()
+                .links(s)
+                .filter(|l| *l.kind() == linkify::LinkKind::Url)
+                .collect();
+            assert_eq!(links.len(), 1);
+            let raw_link = links[0].as_str().to_owned();
+            let mut confirmation_link = reqwest::Url::parse(&raw_link).unwrap();
+            // Make sure we don't call random APIs on the web
+            assert_eq!(confirmation_link.host_str().unwrap(), "127.0.0.1");
+            // Rewrite the URL to include the port, since the confirmation link
+            // only has the subscriber-facing host baked in.
+            confirmation_link.set_port(Some(self.port)).unwrap();
+            confirmation_link
+        };
+
+        let html = get_link(body["HtmlBody"].as_str().unwrap());
+        let plain_text = get_link(body["TextBody"].as_str().unwrap());
+        ConfirmationLinks { html, plain_text }
+    }
 }
 
 // Spins up an instance of our application
@@ -45,8 +103,14 @@ pub async fn spawn_app() -> TestApp {
     let port = listener.local_addr().unwrap().port();
     let address = format!("http://127.0.0.1:{}", port);
 
-    let mut configuration = get_configuration().expect("Failed to read configuration.");
+    // Launch a mock server to stand in for Postmark and point the `EmailClient`
+    // at it, so tests can assert on outbound email without hitting a real provider.
+    let email_server = MockServer::start().await;
+
+    let mut configuration = get_configuration()
+        .expect("Failed to read configuration. Is the `configuration/` directory present?");
     configuration.database.database_name = Uuid::new_v4().to_string();
+    configuration.email_client.base_url = email_server.uri();
     let connection_pool = configure_database(&configuration.database).await;
 
     // Build a new `EmailClient`
@@ -61,16 +125,28 @@ pub async fn spawn_app() -> TestApp {
         configuration.email_client.authorization_token,
         timeout,
     );
-    let server =
-        run(listener, connection_pool.clone(), email_client).expect("Failed to bind address");
-    // Launch the server as a background task
-    // tokio::spawn returns a handle to the spawned future
-    // but we have no use for it here, hence the non-binding let
-    let _ = tokio::spawn(server);
+    let server = run(
+        listener,
+        connection_pool.clone(),
+        email_client,
+        address.clone(),
+    )
+    .expect("Failed to bind address");
+    // Launch the server as a background task; we keep the handle alive by
+    // naming it so the task isn't detached for no reason, but we never await it
+    let _server_handle = tokio::spawn(server);
+
+    let api_client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .expect("Failed to build reqwest client.");
 
     TestApp {
         address,
+        port,
         db_pool: connection_pool,
+        api_client,
+        email_server,
     }
 }
 /*
@@ -83,7 +159,7 @@ async fn configure_database(config: &DatabaseSettings) -> PgPool {
         .await
         .expect("Failed to connect to Postgres");
     connection
-        .execute(&*format!(r#"CREATE DATABASE "{}";"#, config.database_name).as_str())
+        .execute(format!(r#"CREATE DATABASE "{}";"#, config.database_name).as_str())
         .await
         .expect("Failed to create database.");
 
@@ -97,4 +173,4 @@ async fn configure_database(config: &DatabaseSettings) -> PgPool {
         .expect("Failed to migrate the database");
 
     connection_pool
-}
\ No newline at end of file
+}
@@ -0,0 +1,4 @@
+mod health_check;
+mod helpers;
+mod subscriptions;
+mod subscriptions_confirm;
@@ -0,0 +1,148 @@
+use crate::helpers::spawn_app;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn subscribe_returns_a_200_for_valid_form_data() {
+    // Arrange
+    let app = spawn_app().await;
+    Mock::given(path("/email"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&app.email_server)
+        .await;
+
+    // Act
+    // valid pair of name/email uses application/x-www-form-urlencoded
+    // (spaces are %20, @ becomes %40)
+    let body = "name=le%20guin&email=ursula_le_guin%40gmail.com".to_string();
+    let response = app.post_subscriptions(body).await;
+
+    // Assert
+    assert_eq!(200, response.status().as_u16());
+}
+
+#[tokio::test]
+async fn subscribe_sends_a_confirmation_email_with_a_link() {
+    // Arrange
+    let app = spawn_app().await;
+    let body = "name=le%20guin&email=ursula_le_guin%40gmail.com".to_string();
+
+    Mock::given(path("/email"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&app.email_server)
+        .await;
+
+    // Act
+    app.post_subscriptions(body).await;
+
+    // Assert
+    let email_request = &app.email_server.received_requests().await.unwrap()[0];
+    let confirmation_links = app.get_confirmation_links(email_request);
+
+    // The two links extracted from the request body should be identical
+    assert_eq!(confirmation_links.html, confirmation_links.plain_text);
+}
+
+#[tokio::test]
+async fn subscribe_persists_the_new_subscriber_as_pending_confirmation() {
+    // Arrange
+    let app = spawn_app().await;
+    Mock::given(path("/email"))
+        .and(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&app.email_server)
+        .await;
+    let body = "name=le%20guin&email=ursula_le_guin%40gmail.com".to_string();
+
+    // Act
+    app.post_subscriptions(body).await;
+
+    // Assert
+    let saved = sqlx::query!("SELECT email, name, status FROM subscriptions",)
+        .fetch_one(&app.db_pool)
+        .await
+        .expect("Failed to fetch saved subscription.");
+
+    assert_eq!(saved.email, "ursula_le_guin@gmail.com");
+    assert_eq!(saved.name, "le guin");
+    assert_eq!(saved.status, "pending_confirmation");
+}
+
+// Example of table-driven test (also known as parametrized test)
+// In parametrized tests it is important to have good error messages
+// Crate rstest helps with parametrized tests -
+// when rolling your own as soon as 1 fails you wont know about the others
+#[tokio::test]
+async fn subscribe_returns_400_when_data_is_missing() {
+    // Arrange
+    let app = spawn_app().await;
+    let test_cases = vec![
+        ("name=le%20guin", "missing the email"),
+        ("email=ursula_le_guin%40gmail.com", "missing the name"),
+        ("", "missing both name and email"),
+    ];
+
+    for (invalid_body, error_message) in test_cases {
+        // Act
+        let response = app.post_subscriptions(invalid_body.to_string()).await;
+
+        // Assert
+        assert_eq!(
+            400,
+            response.status().as_u16(),
+            // Additional customized error message on test failure
+            "The API did not fail with 400 Bad Request when the payload was {}.",
+            error_message
+        );
+    }
+}
+
+#[tokio::test]
+async fn subscribe_returns_400_when_fields_are_present_but_invalid() {
+    // Arrange
+    let app = spawn_app().await;
+    let test_cases = vec![
+        (
+            "name=%20&email=ursula_le_guin%40gmail.com".to_string(),
+            "empty name",
+        ),
+        (
+            format!("name={}&email=ursula_le_guin%40gmail.com", "X".repeat(257)),
+            "name too long",
+        ),
+        (
+            "name=Tom%2FDick&email=ursula_le_guin%40gmail.com".to_string(),
+            "name contains a forbidden character",
+        ),
+        (
+            "name=Ursula&email=definitely-not-an-email".to_string(),
+            "invalid email",
+        ),
+        (
+            "name=Ursula&email=ursulagmail.com".to_string(),
+            "email missing the @ symbol",
+        ),
+        (
+            "name=Ursula&email=%40gmail.com".to_string(),
+            "email missing the subject",
+        ),
+    ];
+
+    for (body, description) in test_cases {
+        // Act
+        let response = app.post_subscriptions(body).await;
+
+        // Assert
+        assert_eq!(
+            400,
+            response.status().as_u16(),
+            "The API did not return 400 Bad Request when the payload was {}.",
+            description
+        );
+    }
+}
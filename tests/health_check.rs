@@ -1,95 +0,0 @@
-use std::net::TcpListener;
-use zero2prod::startup::run;
-
-// Spins up an instance of our application
-// and returns its address (ie. http://localhost:xxxx)
-fn spawn_app() -> String {
-    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to random port");
-    let port = listener.local_addr().unwrap().port();
-    let server = run(listener).expect("Failed to bind address");
-    // Launch the server as a background task
-    // tokio::spawn returns a handle to the spawned future
-    //but we have no use for it here, hence the non-binding let
-    let _ = tokio::spawn(server);
-
-    // We return the application address to the caller
-    format!("http://127.0.0.1:{}", port)
-}
-
-#[tokio::test]
-async fn health_check_works() {
-    // Arrange
-    let address = spawn_app();
-    // We need to bring in 'request'
-    // to perform HTTP requests against out application
-    let client = reqwest::Client::new();
-
-    // Act
-    let response = client
-        // Use the returned application address
-        .get(&format!("{}/health_check", &address))
-        .send()
-        .await
-        .expect("Failed to execute request");
-
-    // Assert
-    assert!(response.status().is_success());
-    assert_eq!(Some(0), response.content_length());
-}
-
-#[tokio::test]
-async fn subscribe_returns_a_200_for_valid_form_data() {
-    // Arrange
-    let app_address = spawn_app();
-    let client = reqwest::Client::new();
-
-    // Act
-    // valid pair of name/email uses application/x-www-form-urlencoded
-    // (spaces are %20, @ becomes %40)
-    let body = "name=le%20guin&email=ursula_le_guin%40gmail.com";
-    let response = client
-        .post(&format!("{}/subscriptions", &app_address))
-        .header("Content-Type", "application/x-www-form-urlencoded")
-        .body(body)
-        .send()
-        .await
-        .expect("Failed to execute request.");
-
-    // Assert
-    assert_eq!(200, response.status().as_u16());
-}
-// Example of table-driven test (also known as parametrized test)
-// In parametrized tests it is important to have good error messages
-// Crate rstest helps with parametrized tests -
-// when rolling your own as soon as 1 fails you wont know about the others
-#[tokio::test]
-async fn subscribe_returns_400_when_data_is_missing() {
-    // Arrange
-    let app_address = spawn_app();
-    let client = reqwest::Client::new();
-    let test_cases = vec![
-        ("name=le%20guin", "missing the email"),
-        ("email=ursula_le_guin%40gmail.com", "missing the name"),
-        ("", "missing both name and email"),
-    ];
-
-    for (invalid_body, error_message) in test_cases {
-        // Act
-        let response = client
-            .post(&format!("{}/subscriptions", &app_address))
-            .header("Content-Type", "application/x-www-form-urlencoded")
-            .body(invalid_body)
-            .send()
-            .await
-            .expect("Failed to execute request.");
-
-        // Assert
-        assert_eq!(
-            400,
-            response.status().as_u16(),
-            // Additional customized error message on test failure
-            "The API did not fail with 400 Bad Request when the payload was {}.",
-            error_message
-        );
-    }
-}